@@ -5,6 +5,9 @@
  * 1. Miette: For handled, structured errors.
  * 2. Color-Eyre: For unhandled panics and developer context.
  * 3. File: Structured JSON logs in ./logs/api-errors.log.
+ *
+ * The log directory can be moved with ERRORS_LIB_LOG_DIR, and the
+ * file sink dropped entirely with --no-file-log.
  */
 
 use errors_lib::{perform_task, handle_error_logic, CliResult, ReportExt};
@@ -15,16 +18,27 @@ fn main() -> CliResult<()> {
     // This provides suggestions and backtrace cleaning if the app crashes.
     color_eyre::install().expect("Failed to install color-eyre");
 
-    // 2. Setup file appender
-    let file_appender = tracing_appender::rolling::daily("logs", "api-errors.log");
-    let (non_blocking, _guard) = tracing_appender::non_blocking(file_appender);
+    // 2. Setup file appender (unless opted out)
+    // The guard must live until exit so buffered lines are flushed.
+    let no_file_log = std::env::args_os().skip(1).any(|arg| arg == "--no-file-log");
+    let (file_layer, _guard) = if no_file_log {
+        (None, None)
+    } else {
+        let log_dir = std::env::var("ERRORS_LIB_LOG_DIR")
+            .ok()
+            .filter(|dir| !dir.is_empty())
+            .unwrap_or_else(|| "logs".to_string());
+        let file_appender = tracing_appender::rolling::daily(log_dir, "api-errors.log");
+        let (non_blocking, guard) = tracing_appender::non_blocking(file_appender);
+        (Some(fmt::layer().json().with_writer(non_blocking)), Some(guard))
+    };
 
     // 3. Respect RUST_LOG or default to 'off'
     let filter = EnvFilter::try_from_default_env()
         .unwrap_or_else(|_| EnvFilter::new("off"));
 
     tracing_subscriber::registry()
-        .with(fmt::layer().json().with_writer(non_blocking))
+        .with(file_layer)
         .with(
             fmt::layer()
                 .with_writer(std::io::stderr)
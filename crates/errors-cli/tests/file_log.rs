@@ -0,0 +1,83 @@
+/*
+ * Integration tests for the file log sink.
+ * * Runs the real binary in a scratch directory and inspects what it leaves behind.
+ */
+
+use std::ffi::OsStr;
+use std::path::Path;
+use std::process::{Command, Output};
+
+fn run_cli<S: AsRef<OsStr>>(dir: &Path, args: &[S], envs: &[(&str, &OsStr)]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_errors-cli"))
+        .current_dir(dir)
+        .args(args)
+        .env_remove("ERRORS_LIB_LOG_DIR")
+        .envs(envs.iter().copied())
+        .output()
+        .expect("Failed to run errors-cli")
+}
+
+/// `perform_task` always fails, so a healthy run exits non-zero without panicking.
+fn assert_clean_failure(output: &Output) {
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(!stderr.contains("panicked"), "errors-cli panicked:\n{stderr}");
+    assert!(!output.status.success(), "perform_task should make errors-cli exit non-zero");
+}
+
+fn log_files(dir: &Path) -> Vec<String> {
+    std::fs::read_dir(dir)
+        .expect("log dir should exist")
+        .map(|e| e.unwrap().file_name().to_string_lossy().into_owned())
+        .filter(|name| name.starts_with("api-errors.log"))
+        .collect()
+}
+
+#[test]
+fn test_default_writes_to_logs_dir() {
+    let tmp = tempfile::tempdir().unwrap();
+    let output = run_cli::<&str>(tmp.path(), &[], &[]);
+
+    assert_clean_failure(&output);
+    assert!(tmp.path().join("logs").is_dir(), "default run should create ./logs");
+}
+
+#[test]
+fn test_no_file_log_skips_logs_dir() {
+    let tmp = tempfile::tempdir().unwrap();
+    let output = run_cli(tmp.path(), &["--no-file-log"], &[]);
+
+    assert_clean_failure(&output);
+    assert!(!tmp.path().join("logs").exists(), "--no-file-log must not create ./logs");
+}
+
+#[test]
+fn test_log_dir_env_override() {
+    let tmp = tempfile::tempdir().unwrap();
+    let custom = tmp.path().join("custom-logs");
+    let output = run_cli::<&str>(tmp.path(), &[], &[("ERRORS_LIB_LOG_DIR", custom.as_os_str())]);
+
+    assert_clean_failure(&output);
+    assert!(!tmp.path().join("logs").exists());
+    assert!(!log_files(&custom).is_empty());
+}
+
+#[test]
+fn test_empty_log_dir_env_falls_back_to_logs() {
+    let tmp = tempfile::tempdir().unwrap();
+    let output = run_cli::<&str>(tmp.path(), &[], &[("ERRORS_LIB_LOG_DIR", OsStr::new(""))]);
+
+    assert_clean_failure(&output);
+    assert!(log_files(tmp.path()).is_empty(), "empty ERRORS_LIB_LOG_DIR must not log into the cwd");
+    assert!(!log_files(&tmp.path().join("logs")).is_empty());
+}
+
+#[cfg(unix)]
+#[test]
+fn test_non_utf8_argument_does_not_panic() {
+    use std::os::unix::ffi::OsStrExt;
+
+    let tmp = tempfile::tempdir().unwrap();
+    let output = run_cli(tmp.path(), &[OsStr::from_bytes(b"\xff")], &[]);
+
+    assert_clean_failure(&output);
+}
@@ -15,6 +15,7 @@ use rootcause::Report;
 use serde::{Serialize, Serializer};
 use tracing::error;
 use nanoid::nanoid;
+use std::sync::OnceLock;
 
 pub use rootcause;
 pub use miette::Result as CliResult;
@@ -24,6 +25,34 @@ pub struct LibReport(pub Report<LibError>);
 
 pub type LibResult<T> = std::result::Result<T, LibReport>;
 
+/// Upper bound on frames kept in `ApiError::history`; the rest collapse into a sentinel.
+pub const MAX_HISTORY_FRAMES: usize = 32;
+
+/* * SERVICE METADATA OVERRIDES
+ * GIT_HASH and ERROR_DOCS_URL are baked in when errors-lib is compiled.
+ * An embedding service can replace them once at startup with its own.
+ */
+static SERVICE_GIT_HASH: OnceLock<String> = OnceLock::new();
+static SERVICE_DOCS_URL: OnceLock<String> = OnceLock::new();
+
+/// Reports `hash` instead of errors-lib's own git hash. Only the first call wins.
+pub fn set_service_git_hash(hash: &str) -> Result<(), String> {
+    SERVICE_GIT_HASH.set(hash.to_string())
+}
+
+/// Reports `url` instead of errors-lib's docs URL. Only the first call wins.
+pub fn set_service_docs_url(url: &str) -> Result<(), String> {
+    SERVICE_DOCS_URL.set(url.to_string())
+}
+
+fn service_git_hash() -> &'static str {
+    SERVICE_GIT_HASH.get().map_or(env!("GIT_HASH"), String::as_str)
+}
+
+fn service_docs_url() -> &'static str {
+    SERVICE_DOCS_URL.get().map_or(env!("ERROR_DOCS_URL"), String::as_str)
+}
+
 #[derive(Debug, Serialize)]
 pub struct ErrorFrame {
     pub message: String,
@@ -67,7 +96,7 @@ impl Diagnostic for LibReport {
      * Maps the error code to a clickable link in the terminal.
      */
     fn url<'a>(&'a self) -> Option<Box<dyn std::fmt::Display + 'a>> {
-        let base = env!("ERROR_DOCS_URL");
+        let base = service_docs_url();
         self.code().map(|c| {
             let link = format!("{}/#{}", base, c);
             Box::new(link) as Box<dyn std::fmt::Display>
@@ -104,10 +133,16 @@ impl ReportExt for LibReport {
             }
         }
 
+        if history.len() > MAX_HISTORY_FRAMES {
+            let omitted = history.len() - MAX_HISTORY_FRAMES;
+            history.truncate(MAX_HISTORY_FRAMES);
+            history.push(ErrorFrame { message: format!("... ({omitted} more frames omitted)") });
+        }
+
         let ctx = self.0.current_context();
         let api_err = ApiError {
-            git_hash: env!("GIT_HASH").to_string(),
-            docs_url: env!("ERROR_DOCS_URL").to_string(),
+            git_hash: service_git_hash().to_string(),
+            docs_url: service_docs_url().to_string(),
             correlation_id: nanoid!(8),
             title: ctx.to_string(),
             code: LibError::code(ctx).map(|c| c.to_string()),
@@ -131,10 +166,10 @@ impl ReportExt for LibReport {
 
 pub fn handle_error_logic(report: &LibReport) {
     for node in report.0.iter_reports() {
-        if let Some(io_err) = node.downcast_current_context::<std::io::Error>() {
-            if matches!(io_err.kind(), std::io::ErrorKind::NotFound) {
-                println!("--- LOGIC CHECK: Missing file detected ---");
-            }
+        if let Some(io_err) = node.downcast_current_context::<std::io::Error>()
+            && matches!(io_err.kind(), std::io::ErrorKind::NotFound)
+        {
+            println!("--- LOGIC CHECK: Missing file detected ---");
         }
     }
 }
@@ -3,7 +3,9 @@
  * * This uses snapshot testing to ensure the JSON structure remains stable.
  */
 
-use errors_lib::{perform_task, ReportExt};
+use errors_lib::rootcause::Report;
+use errors_lib::types::LibError;
+use errors_lib::{perform_task, LibReport, ReportExt, MAX_HISTORY_FRAMES};
 use serde_json::Value;
 
 #[test]
@@ -45,3 +47,21 @@ fn test_snapshot_api_error() {
     // This will create/check a file in tests/snapshots/
     insta::assert_json_snapshot!(redacted);
 }
+
+#[test]
+fn test_history_truncation() {
+    // 1. Build a report with far more attachments than the limit
+    let mut report = Report::new(LibError::NetworkError { timeout: 30 });
+    for i in 0..50 {
+        report = report.attach(format!("retry attempt {i}"));
+    }
+
+    let api_error = LibReport(report).to_api_error();
+
+    // 2. The history is capped, plus one sentinel frame
+    assert_eq!(api_error.history.len(), MAX_HISTORY_FRAMES + 1);
+
+    // 3. 50 attachments + the creation location = 51 frames, 19 dropped
+    let sentinel = &api_error.history.last().unwrap().message;
+    assert_eq!(sentinel, "... (19 more frames omitted)");
+}
@@ -0,0 +1,23 @@
+/*
+ * Integration tests for service metadata overrides.
+ * * Kept in its own test binary because the overrides are process-global.
+ */
+
+use errors_lib::{perform_task, set_service_docs_url, set_service_git_hash, ReportExt};
+
+#[test]
+fn test_service_overrides_in_api_error() {
+    // 1. Install the overrides once
+    set_service_git_hash("deadbee").expect("git hash already set");
+    set_service_docs_url("https://docs.example.com/errors").expect("docs url already set");
+
+    // 2. The produced ApiError reports the service's metadata
+    let api_error = perform_task().unwrap_err().to_api_error();
+    assert_eq!(api_error.git_hash, "deadbee");
+    assert_eq!(api_error.docs_url, "https://docs.example.com/errors");
+
+    // 3. A second call is rejected and leaves the first value in place
+    assert!(set_service_git_hash("cafef00d").is_err());
+    let api_error = perform_task().unwrap_err().to_api_error();
+    assert_eq!(api_error.git_hash, "deadbee");
+}